@@ -1,16 +1,361 @@
 use std::fmt::Debug;
-use wasm_bindgen::prelude::*;
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = console)]
-    fn log(s: &str);
+#[cfg(target_arch = "wasm32")]
+mod bindings {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn log(s: &str);
+
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn error(s: &str);
+
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn warn(s: &str);
+
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn info(s: &str);
+
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn debug(s: &str);
+
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn time(label: &str);
+
+        #[wasm_bindgen(js_namespace = console, js_name = timeEnd)]
+        pub fn time_end(label: &str);
+
+        #[wasm_bindgen(js_namespace = console, js_name = timeLog)]
+        pub fn time_log(label: &str, value: &str);
+    }
+}
+
+/// Native fallback for the `console` bindings, used when this crate is built
+/// for a non-wasm32 target (e.g. `cargo test`), where the JS `console` import
+/// can't be linked.
+#[cfg(not(target_arch = "wasm32"))]
+mod bindings {
+    pub fn log(s: &str) {
+        eprintln!("{}", s);
+    }
+
+    pub fn error(s: &str) {
+        eprintln!("{}", s);
+    }
+
+    pub fn warn(s: &str) {
+        eprintln!("{}", s);
+    }
+
+    pub fn info(s: &str) {
+        eprintln!("{}", s);
+    }
+
+    pub fn debug(s: &str) {
+        eprintln!("{}", s);
+    }
+
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::time::Instant;
+
+    thread_local! {
+        static TIMERS: RefCell<HashMap<String, Instant>> = RefCell::new(HashMap::new());
+    }
+
+    pub fn time(label: &str) {
+        TIMERS.with(|timers| {
+            let mut timers = timers.borrow_mut();
+            if timers.contains_key(label) {
+                eprintln!("Timer '{}' already exists", label);
+            }
+            timers.insert(label.to_string(), Instant::now());
+        });
+    }
+
+    pub fn time_end(label: &str) {
+        let elapsed = TIMERS.with(|timers| timers.borrow_mut().remove(label));
+        match elapsed {
+            Some(start) => eprintln!("{}: {:?}", label, start.elapsed()),
+            None => eprintln!("Timer '{}' does not exist", label),
+        }
+    }
+
+    pub fn time_log(label: &str, value: &str) {
+        let elapsed = TIMERS.with(|timers| timers.borrow().get(label).map(|start| start.elapsed()));
+        match elapsed {
+            Some(elapsed) => eprintln!("{}: {:?} {}", label, elapsed, value),
+            None => eprintln!("Timer '{}' does not exist", label),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn timer_exists(label: &str) -> bool {
+            TIMERS.with(|timers| timers.borrow().contains_key(label))
+        }
+
+        #[test]
+        fn time_inserts_a_running_timer() {
+            assert!(!timer_exists("insert"));
+            time("insert");
+            assert!(timer_exists("insert"));
+        }
+
+        #[test]
+        fn time_end_removes_the_timer() {
+            time("remove");
+            time_end("remove");
+            assert!(!timer_exists("remove"));
+        }
+
+        #[test]
+        fn time_end_on_missing_label_does_not_panic() {
+            time_end("never-started");
+        }
+
+        #[test]
+        fn time_log_on_missing_label_does_not_panic() {
+            time_log("never-started", "checkpoint");
+        }
+
+        #[test]
+        fn time_log_does_not_remove_the_timer() {
+            time("log");
+            time_log("log", "checkpoint");
+            assert!(timer_exists("log"));
+            time_end("log");
+        }
+
+        #[test]
+        fn starting_an_existing_timer_restarts_it_instead_of_panicking() {
+            time("restart");
+            time("restart");
+            assert!(timer_exists("restart"));
+            time_end("restart");
+        }
+    }
+}
+
+/// Severity of a console log message, mirroring the levels exposed by the
+/// browser's `console` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+/// Dispatches `string` to the console binding matching `level`.
+pub fn log_at(level: LogLevel, string: &str) {
+    match level {
+        LogLevel::Error => console_error(string),
+        LogLevel::Warn => console_warn(string),
+        LogLevel::Info => console_info(string),
+        LogLevel::Debug => console_debug(string),
+    }
 }
 
 pub fn console_log(string: &str) {
-    log(string);
+    bindings::log(string);
 }
 
 pub fn console_log_any<T: Debug>(string: &T) {
-    log(format!("{:?}", string).as_str());
+    bindings::log(format!("{:?}", string).as_str());
+}
+
+pub fn console_error(string: &str) {
+    bindings::error(string);
+}
+
+pub fn console_error_any<T: Debug>(string: &T) {
+    bindings::error(format!("{:?}", string).as_str());
+}
+
+pub fn console_warn(string: &str) {
+    bindings::warn(string);
+}
+
+pub fn console_warn_any<T: Debug>(string: &T) {
+    bindings::warn(format!("{:?}", string).as_str());
+}
+
+pub fn console_info(string: &str) {
+    bindings::info(string);
+}
+
+pub fn console_info_any<T: Debug>(string: &T) {
+    bindings::info(format!("{:?}", string).as_str());
+}
+
+pub fn console_debug(string: &str) {
+    bindings::debug(string);
+}
+
+pub fn console_debug_any<T: Debug>(string: &T) {
+    bindings::debug(format!("{:?}", string).as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn console_functions_do_not_panic_on_native() {
+        console_log("a log message");
+        console_error("an error message");
+        console_warn("a warning message");
+        console_info("an info message");
+        console_debug("a debug message");
+    }
+
+    #[test]
+    fn console_any_functions_format_with_debug() {
+        console_log_any(&("tx_hash", 42));
+        console_error_any(&Some("missing section"));
+        console_warn_any(&vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn log_at_accepts_every_level_without_panicking() {
+        log_at(LogLevel::Error, "routed to console.error");
+        log_at(LogLevel::Warn, "routed to console.warn");
+        log_at(LogLevel::Info, "routed to console.info");
+        log_at(LogLevel::Debug, "routed to console.debug");
+    }
+}
+
+/// Starts a named timer, mirroring `console.time(label)`.
+pub fn time_start(label: &str) {
+    bindings::time(label);
+}
+
+/// Stops a named timer and logs its duration, mirroring `console.timeEnd(label)`.
+pub fn time_end(label: &str) {
+    bindings::time_end(label);
+}
+
+/// Logs the current value of a running timer without stopping it, mirroring
+/// `console.timeLog(label, value)`.
+pub fn time_log(label: &str, value: &str) {
+    bindings::time_log(label, value);
+}
+
+/// An RAII guard that starts a named timer on construction and stops it when
+/// dropped, so profiling a scope is as simple as:
+///
+/// ```ignore
+/// let _t = ScopedTimer::new("build_tx");
+/// ```
+pub struct ScopedTimer {
+    label: String,
+}
+
+impl ScopedTimer {
+    pub fn new(label: &str) -> Self {
+        time_start(label);
+        Self {
+            label: label.to_string(),
+        }
+    }
+}
+
+impl Drop for ScopedTimer {
+    fn drop(&mut self) {
+        time_end(&self.label);
+    }
+}
+
+/// A `log::Log` implementation that routes records to the console bindings
+/// above, so crates using the `log` facade (`log::info!`, etc.) surface their
+/// output in browser devtools without changing call sites.
+pub struct ConsoleLogger;
+
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let message = format!("{}: {}", record.target(), record.args());
+        let level = match record.level() {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug | log::Level::Trace => LogLevel::Debug,
+        };
+        log_at(level, &message);
+    }
+
+    fn flush(&self) {}
+}
+
+static CONSOLE_LOGGER: ConsoleLogger = ConsoleLogger;
+
+/// Registers [`ConsoleLogger`] as the global `log` logger, so that any
+/// dependency emitting records through `log::info!`/`log::error!`/etc. has
+/// its output routed to the console bindings. `max_level` caps which records
+/// are dispatched.
+pub fn init(max_level: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+    log::set_logger(&CONSOLE_LOGGER)?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod console_logger_tests {
+    use super::*;
+    use log::Log;
+    use std::sync::Mutex;
+
+    // `log::set_max_level` is process-global, so tests that mutate it must
+    // not run concurrently with each other.
+    static MAX_LEVEL_GUARD: Mutex<()> = Mutex::new(());
+
+    fn record(level: log::Level) -> log::Metadata<'static> {
+        log::Metadata::builder().level(level).target("masp_web").build()
+    }
+
+    #[test]
+    fn enabled_respects_the_global_max_level() {
+        let _guard = MAX_LEVEL_GUARD.lock().unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+
+        assert!(ConsoleLogger.enabled(&record(log::Level::Error)));
+        assert!(ConsoleLogger.enabled(&record(log::Level::Warn)));
+        assert!(!ConsoleLogger.enabled(&record(log::Level::Info)));
+        assert!(!ConsoleLogger.enabled(&record(log::Level::Debug)));
+
+        log::set_max_level(log::LevelFilter::Trace);
+    }
+
+    #[test]
+    fn log_formats_target_and_message_without_panicking() {
+        let _guard = MAX_LEVEL_GUARD.lock().unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        for level in [
+            log::Level::Error,
+            log::Level::Warn,
+            log::Level::Info,
+            log::Level::Debug,
+            log::Level::Trace,
+        ] {
+            let record = log::Record::builder()
+                .level(level)
+                .target("masp_web::utils")
+                .args(format_args!("building tx"))
+                .build();
+            ConsoleLogger.log(&record);
+        }
+    }
 }